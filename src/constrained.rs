@@ -0,0 +1,226 @@
+//! Constrained Delaunay triangulation: forced boundary edges and polygon holes.
+
+use asprim::AsPrim;
+use num_traits::int::PrimInt;
+
+use crate::{orient, Coordinate, Triangulation};
+
+/// Result of [`Triangulation::constrain`]: the Delaunay triangulation with
+/// `edges` forced in, plus enough bookkeeping for callers to drop holes.
+pub struct ConstrainedTriangulation<T: AsPrim + PrimInt> {
+    /// The triangulation, with every requested constraint edge present as a
+    /// half-edge pair.
+    pub triangulation: Triangulation<T>,
+
+    /// Indexed like [`Triangulation::halfedges`]: `true` for the half-edges
+    /// (and their twins) that were forced in by a constraint edge. Future
+    /// re-legalization of the mesh should leave these alone.
+    pub constrained: Vec<bool>,
+
+    /// One entry per triangle (`triangulation.len()` long). `false` for
+    /// triangles flood-filled out of a hole polygon; callers building a
+    /// navmesh or renderable should skip those.
+    pub inside: Vec<bool>,
+}
+
+impl<T: AsPrim + PrimInt> Triangulation<T> {
+    /// Force `edges` (pairs of point indices) into this triangulation and
+    /// flood-fill each polygon in `holes` (closed loops of point indices,
+    /// wound the same way as [`Triangulation::hull`], i.e.
+    /// counter-clockwise) to tag the triangles inside it as removed.
+    ///
+    /// For every edge `(a, b)` not already present, this scans the mesh for
+    /// the edges the segment crosses and flips them (the same
+    /// mutation `legalize` uses to fix up illegal triangle pairs, but
+    /// applied unconditionally) until `a`-`b` appears, then locks the
+    /// resulting half-edge pair so it won't be flipped again. Each hole
+    /// is seeded from one of its own boundary edges and flood-fills across
+    /// unlocked half-edges, so hole boundaries should also be listed in
+    /// `edges` or the fill will leak into the rest of the mesh.
+    pub fn constrain<C: Coordinate>(
+        mut self,
+        points: &[C],
+        edges: &[(usize, usize)],
+        holes: &[Vec<usize>],
+    ) -> ConstrainedTriangulation<T> {
+        let mut constrained = vec![false; self.halfedges.len()];
+
+        for &(a, b) in edges {
+            let a: T = a.as_();
+            let b: T = b.as_();
+            if a != b {
+                self.insert_constraint_edge(a, b, points, &mut constrained);
+            }
+        }
+
+        let mut inside = vec![true; self.len()];
+        for hole in holes {
+            if let Some(seed) = self.hole_seed_halfedge(hole) {
+                self.flood_hole(seed, &constrained, &mut inside);
+            }
+        }
+
+        ConstrainedTriangulation {
+            triangulation: self,
+            constrained,
+            inside,
+        }
+    }
+
+    /// Force the edge `a`-`b` into the mesh, flipping crossed diagonals
+    /// until it appears, then lock the resulting half-edge pair.
+    fn insert_constraint_edge<C: Coordinate>(
+        &mut self,
+        a: T,
+        b: T,
+        points: &[C],
+        constrained: &mut [bool],
+    ) {
+        // bail out after more flips than edges exist in the mesh: a
+        // well-formed input always converges long before this, so hitting
+        // it means the constraint can't be satisfied (e.g. it crosses
+        // another constraint edge) and we give up rather than loop forever
+        let mut budget = self.halfedges.len() + 1;
+
+        loop {
+            if let Some(e) = self.find_halfedge(a, b) {
+                self.lock_edge(e, constrained);
+                return;
+            }
+
+            if budget == 0 {
+                return;
+            }
+            budget -= 1;
+
+            match self
+                .find_crossing_edges(a, b, points)
+                .into_iter()
+                .find(|&e| !constrained[e.as_usize()] && self.is_convex_quad(e, points))
+            {
+                Some(e) => self.flip_edge(e),
+                None => return, // nothing left to flip towards a-b; best effort
+            }
+        }
+    }
+
+    /// The half-edge `e` with `origin(e) == a` and `origin(next(e)) == b`,
+    /// if the directed edge `a -> b` is already in the mesh.
+    fn find_halfedge(&self, a: T, b: T) -> Option<T> {
+        for e in 0..self.triangles.len() {
+            let e: T = e.as_();
+            if self.origin(e) == a && self.origin(Self::next_halfedge(e)) == b {
+                return Some(e);
+            }
+        }
+        None
+    }
+
+    /// Every interior half-edge (smaller of the two twins, so each edge is
+    /// reported once) whose segment properly crosses segment `a`-`b`.
+    fn find_crossing_edges<C: Coordinate>(&self, a: T, b: T, points: &[C]) -> Vec<T> {
+        let pa = &points[a.as_usize()];
+        let pb = &points[b.as_usize()];
+
+        let mut crossing = Vec::new();
+        for e in 0..self.triangles.len() {
+            let e: T = e.as_();
+            let twin = self.twin(e);
+            if twin == self.empty || e >= twin {
+                continue;
+            }
+
+            let p = self.origin(e);
+            let q = self.origin(Self::next_halfedge(e));
+            if p == a || p == b || q == a || q == b {
+                continue;
+            }
+
+            let pp = &points[p.as_usize()];
+            let pq = &points[q.as_usize()];
+            if orient(pa, pb, pp) != orient(pa, pb, pq) && orient(pp, pq, pa) != orient(pp, pq, pb) {
+                crossing.push(e);
+            }
+        }
+        crossing
+    }
+
+    /// Whether flipping the shared edge of half-edge `e` and its twin
+    /// replaces a convex quadrilateral's diagonal (and so doesn't fold the
+    /// mesh over itself).
+    fn is_convex_quad<C: Coordinate>(&self, e: T, points: &[C]) -> bool {
+        let twin = self.twin(e);
+        let r = self.origin(Self::prev_halfedge(e));
+        let s = self.origin(Self::prev_halfedge(twin));
+        let p = self.origin(e);
+        let q = self.origin(Self::next_halfedge(e));
+
+        let pr = &points[r.as_usize()];
+        let ps = &points[s.as_usize()];
+        orient(pr, ps, &points[p.as_usize()]) != orient(pr, ps, &points[q.as_usize()])
+    }
+
+    /// Mark half-edge `e` and its twin as forced constraint edges.
+    fn lock_edge(&self, e: T, constrained: &mut [bool]) {
+        constrained[e.as_usize()] = true;
+        let twin = self.twin(e);
+        if twin != self.empty {
+            constrained[twin.as_usize()] = true;
+        }
+    }
+
+    /// The half-edge that runs along `hole`'s boundary into its interior,
+    /// used to seed the flood fill. `hole` is wound counter-clockwise, so
+    /// the triangle owning the directed edge `hole[i] -> hole[i + 1]` is
+    /// the one inside the hole.
+    fn hole_seed_halfedge(&self, hole: &[usize]) -> Option<T> {
+        for w in 0..hole.len() {
+            let u: T = hole[w].as_();
+            let v: T = hole[(w + 1) % hole.len()].as_();
+            if let Some(e) = self.find_halfedge(u, v) {
+                return Some(e);
+            }
+        }
+        None
+    }
+
+    /// Flood-fill triangle adjacency from `seed`'s triangle, crossing only
+    /// unlocked half-edges, marking every reached triangle as outside the
+    /// usable mesh.
+    fn flood_hole(&self, seed: T, constrained: &[bool], inside: &mut [bool]) {
+        let mut stack = vec![seed.as_usize() / 3];
+        while let Some(t) = stack.pop() {
+            if !inside[t] {
+                continue;
+            }
+            inside[t] = false;
+
+            for k in 0..3 {
+                let e: T = (t * 3 + k).as_();
+                if constrained[e.as_usize()] {
+                    continue;
+                }
+                let twin = self.twin(e);
+                if twin == self.empty {
+                    continue;
+                }
+                let neighbor = twin.as_usize() / 3;
+                if inside[neighbor] {
+                    stack.push(neighbor);
+                }
+            }
+        }
+    }
+}
+
+/// Triangulate `points`, then force in `edges` (pairs of point indices) and
+/// flood-fill `holes` (polygons of point indices, wound the same way as
+/// [`Triangulation::hull`]) as [`Triangulation::constrain`] describes.
+/// Returns `None` if no Delaunay triangulation exists for `points`.
+pub fn triangulate_constrained<C: Coordinate>(
+    points: &[C],
+    edges: &[(usize, usize)],
+    holes: &[Vec<usize>],
+) -> Option<ConstrainedTriangulation<u32>> {
+    Some(Triangulation::new(points)?.constrain(points, edges, holes))
+}
@@ -0,0 +1,113 @@
+//! Jitter-and-retry fallback for collinear / degenerate input.
+
+use asprim::AsPrim;
+use num_traits::int::PrimInt;
+
+use crate::{Coordinate, Triangulation};
+
+/// Result of [`triangulate_with_jitter`].
+pub struct JitteredTriangulation<T: AsPrim + PrimInt> {
+    /// The triangulation. When `jittered` is set this was computed from
+    /// perturbed copies of the input points rather than the points
+    /// themselves, so positions derived from it (circumcenters, a
+    /// [`crate::Voronoi`] built on top, ...) are only approximate.
+    pub triangulation: Triangulation<T>,
+
+    /// `true` for every input index if a direct triangulation of `points`
+    /// didn't exist (e.g. all points collinear or too close together to
+    /// find a seed triangle) and jittering was needed to produce one at
+    /// all. `false` everywhere if the direct triangulation already
+    /// succeeded and no perturbation was applied.
+    pub jittered: Vec<bool>,
+}
+
+/// A point offset by [`jitter`] from one of `points`, used only to feed
+/// [`Triangulation::new`]; the caller keeps indexing the original `points`
+/// for everything else.
+struct Jittered {
+    x: f64,
+    y: f64,
+}
+
+impl Coordinate for Jittered {
+    fn x(&self) -> f64 {
+        self.x
+    }
+    fn y(&self) -> f64 {
+        self.y
+    }
+}
+
+/// A small deterministic offset for point `i`, scaled to the input's
+/// bounding box so it's far below anything the caller would notice but
+/// large enough to break exact collinearity or duplication. Mirrors the
+/// jitter d3-delaunay applies to recover from the same failure.
+fn jitter(i: usize, scale: f64) -> (f64, f64) {
+    let t = i as f64;
+    (1e-9 * scale * t.sin(), 1e-9 * scale * t.cos())
+}
+
+/// The larger of the input's bounding box width/height, used to scale
+/// [`jitter`] to the data instead of applying a fixed offset that might be
+/// too small (huge coordinates) or too large (tiny coordinates) to matter.
+fn bbox_scale<C: Coordinate>(points: &[C]) -> f64 {
+    let mut min_x = f64::INFINITY;
+    let mut max_x = f64::NEG_INFINITY;
+    let mut min_y = f64::INFINITY;
+    let mut max_y = f64::NEG_INFINITY;
+    for p in points {
+        min_x = min_x.min(p.x());
+        max_x = max_x.max(p.x());
+        min_y = min_y.min(p.y());
+        max_y = max_y.max(p.y());
+    }
+    let width = max_x - min_x;
+    let height = max_y - min_y;
+    let scale = width.max(height);
+    if scale.is_finite() && scale > 0.0 {
+        scale
+    } else {
+        1.0
+    }
+}
+
+/// Triangulate `points`, like [`crate::triangulate`], but fall back to
+/// jittering the input and retrying instead of giving up when no seed
+/// triangle exists (all points collinear, or too close together for any
+/// three to form a triangle).
+///
+/// Each point is offset by a tiny deterministic amount derived from its
+/// index and the input's scale before the retry, then the *original*
+/// `points` slice is what every caller keeps indexing afterwards -
+/// [`JitteredTriangulation::triangulation`] only contains indices, never
+/// the jittered coordinates. Still returns `None` if even the jittered
+/// copy has no triangulation (e.g. fewer than 3 points). Exact duplicates
+/// are offset by different amounts, so they don't by themselves prevent
+/// the retry from succeeding.
+pub fn triangulate_with_jitter<C: Coordinate>(points: &[C]) -> Option<JitteredTriangulation<u32>> {
+    if let Some(triangulation) = Triangulation::new(points) {
+        return Some(JitteredTriangulation {
+            triangulation,
+            jittered: vec![false; points.len()],
+        });
+    }
+
+    let scale = bbox_scale(points);
+    let jittered_points: Vec<Jittered> = points
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let (dx, dy) = jitter(i, scale);
+            Jittered {
+                x: p.x() + dx,
+                y: p.y() + dy,
+            }
+        })
+        .collect();
+
+    let triangulation = Triangulation::new(&jittered_points)?;
+    Some(JitteredTriangulation {
+        triangulation,
+        jittered: vec![true; points.len()],
+    })
+}
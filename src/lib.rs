@@ -17,14 +17,27 @@ let points = vec![
 let result = triangulate(&points).expect("No triangulation exists.");
 println!("{:?}", result.triangles); // [0, 2, 1, 0, 3, 2]
 ```
+
+`triangulate` isn't tied to [`Point`]: it accepts any slice of types implementing
+[`Coordinate`], so `[f64; 2]`, `(f64, f64)`, or a caller's own vertex type work
+without copying into a `Point` first.
 */
 extern crate asprim;
 extern crate num_traits;
 
+mod constrained;
+mod jitter;
+mod predicates;
+mod voronoi;
+
 use std::{f64, fmt};
 use asprim::AsPrim;
 use num_traits::int::PrimInt;
 
+pub use constrained::{triangulate_constrained, ConstrainedTriangulation};
+pub use jitter::{triangulate_with_jitter, JitteredTriangulation};
+pub use voronoi::Voronoi;
+
 /// Near-duplicate points (where both `x` and `y` only differ within this value)
 /// will not be included in the triangulation for robustness.
 pub const EPSILON: f64 = f64::EPSILON * 2.0;
@@ -42,65 +55,144 @@ impl fmt::Debug for Point {
     }
 }
 
-impl Point {
-    fn dist2(&self, p: &Self) -> f64 {
-        let dx = self.x - p.x;
-        let dy = self.y - p.y;
-        dx * dx + dy * dy
+/// A 2D coordinate accessor, implemented for any point-like type.
+///
+/// [`triangulate`]/[`Triangulation::new`] are generic over this trait, so
+/// callers can feed their own vertex types (a `glam::Vec2`, `[f64; 2]`,
+/// `geo::Coord`, ...) straight in without copying into a [`Point`] first.
+pub trait Coordinate {
+    fn x(&self) -> f64;
+    fn y(&self) -> f64;
+}
+
+impl Coordinate for Point {
+    fn x(&self) -> f64 {
+        self.x
     }
+    fn y(&self) -> f64 {
+        self.y
+    }
+}
 
-    fn orient(&self, q: &Self, r: &Self) -> bool {
-        (q.y - self.y) * (r.x - q.x) - (q.x - self.x) * (r.y - q.y) < 0.0
+impl Coordinate for (f64, f64) {
+    fn x(&self) -> f64 {
+        self.0
+    }
+    fn y(&self) -> f64 {
+        self.1
     }
+}
 
-    fn circumdelta(&self, b: &Self, c: &Self) -> (f64, f64) {
-        let dx = b.x - self.x;
-        let dy = b.y - self.y;
-        let ex = c.x - self.x;
-        let ey = c.y - self.y;
+impl Coordinate for [f64; 2] {
+    fn x(&self) -> f64 {
+        self[0]
+    }
+    fn y(&self) -> f64 {
+        self[1]
+    }
+}
 
-        let bl = dx * dx + dy * dy;
-        let cl = ex * ex + ey * ey;
-        let d = 0.5 / (dx * ey - dy * ex);
+fn dist2<A: Coordinate, B: Coordinate>(a: &A, b: &B) -> f64 {
+    let dx = a.x() - b.x();
+    let dy = a.y() - b.y();
+    dx * dx + dy * dy
+}
 
-        let x = (ey * bl - dy * cl) * d;
-        let y = (dx * cl - ex * bl) * d;
-        (x, y)
+/// Adaptive orientation test: a plain `f64` evaluation first, falling back
+/// to [`predicates::orient_exact`] when the result is too close to zero to
+/// trust (the error bound is Shewchuk's static filter for this 2x2
+/// determinant). This guarantees the correct sign even for nearly-collinear
+/// points, where the naive comparison can flip.
+pub(crate) fn orient<C: Coordinate>(p: &C, q: &C, r: &C) -> bool {
+    let qy_py = q.y() - p.y();
+    let rx_qx = r.x() - q.x();
+    let qx_px = q.x() - p.x();
+    let ry_qy = r.y() - q.y();
+
+    let left = qy_py * rx_qx;
+    let right = qx_px * ry_qy;
+    let det = left - right;
+
+    let bound = 3.3306690738754716e-16 * (left.abs() + right.abs());
+    if det.abs() > bound {
+        det < 0.0
+    } else {
+        predicates::orient_exact(p, q, r) < 0
     }
+}
 
-    fn circumradius2(&self, b: &Self, c: &Self) -> f64 {
-        let (x, y) = self.circumdelta(b, c);
-        x * x + y * y
-    }
+fn circumdelta<C: Coordinate>(a: &C, b: &C, c: &C) -> (f64, f64) {
+    let dx = b.x() - a.x();
+    let dy = b.y() - a.y();
+    let ex = c.x() - a.x();
+    let ey = c.y() - a.y();
 
-    fn circumcenter(&self, b: &Self, c: &Self) -> Self {
-        let (x, y) = self.circumdelta(b, c);
-        Self {
-            x: self.x + x,
-            y: self.y + y,
-        }
-    }
+    let bl = dx * dx + dy * dy;
+    let cl = ex * ex + ey * ey;
+    let d = 0.5 / (dx * ey - dy * ex);
 
-    fn in_circle(&self, b: &Self, c: &Self, p: &Self) -> bool {
-        let dx = self.x - p.x;
-        let dy = self.y - p.y;
-        let ex = b.x - p.x;
-        let ey = b.y - p.y;
-        let fx = c.x - p.x;
-        let fy = c.y - p.y;
+    let x = (ey * bl - dy * cl) * d;
+    let y = (dx * cl - ex * bl) * d;
+    (x, y)
+}
 
-        let ap = dx * dx + dy * dy;
-        let bp = ex * ex + ey * ey;
-        let cp = fx * fx + fy * fy;
+fn circumradius2<C: Coordinate>(a: &C, b: &C, c: &C) -> f64 {
+    let (x, y) = circumdelta(a, b, c);
+    x * x + y * y
+}
 
-        dx * (ey * cp - bp * fy) - dy * (ex * cp - bp * fx) + ap * (ex * fy - ey * fx) < 0.0
+pub(crate) fn circumcenter<C: Coordinate>(a: &C, b: &C, c: &C) -> Point {
+    let (x, y) = circumdelta(a, b, c);
+    Point {
+        x: a.x() + x,
+        y: a.y() + y,
     }
+}
 
-    fn nearly_equals(&self, p: &Self) -> bool {
-        (self.x - p.x).abs() <= EPSILON && (self.y - p.y).abs() <= EPSILON
+/// Adaptive in-circle test, mirroring [`orient`]: a plain `f64` evaluation
+/// first, falling back to [`predicates::in_circle_exact`] when the result
+/// is too close to zero to trust (nearly-cocircular points, common with
+/// grid-aligned or clustered input). [`Triangulation::legalize`] relies on
+/// this always returning the exact sign to guarantee it terminates: a
+/// float-only in-circle test can flip back and forth on a cocircular
+/// foursome and flip the same pair of triangles forever.
+fn in_circle<C: Coordinate>(a: &C, b: &C, c: &C, p: &C) -> bool {
+    let dx = a.x() - p.x();
+    let dy = a.y() - p.y();
+    let ex = b.x() - p.x();
+    let ey = b.y() - p.y();
+    let fx = c.x() - p.x();
+    let fy = c.y() - p.y();
+
+    let ap = dx * dx + dy * dy;
+    let bp = ex * ex + ey * ey;
+    let cp = fx * fx + fy * fy;
+
+    let term_a = dx * (ey * cp - bp * fy);
+    let term_b = dy * (ex * cp - bp * fx);
+    let term_c = ap * (ex * fy - ey * fx);
+    let det = term_a - term_b + term_c;
+
+    // A conservative (deliberately loose) bound on the forward rounding
+    // error of the sum above, scaled by its permanent (every product taken
+    // in absolute value, so cancellation inside a term can't shrink the
+    // bound below that term's rounding error), at the cost of falling back
+    // to the exact path a bit more often than a tightly-derived bound would.
+    let permanent = dx.abs() * ((ey * cp).abs() + (bp * fy).abs())
+        + dy.abs() * ((ex * cp).abs() + (bp * fx).abs())
+        + ap * ((ex * fy).abs() + (ey * fx).abs());
+    let bound = 1e-14 * permanent;
+    if det.abs() > bound {
+        det < 0.0
+    } else {
+        predicates::in_circle_exact(a, b, c, p) < 0
     }
 }
 
+fn nearly_equals<C: Coordinate>(a: &C, b: &C) -> bool {
+    (a.x() - b.x()).abs() <= EPSILON && (a.y() - b.y()).abs() <= EPSILON
+}
+
 /// Result of the Delaunay triangulation.
 pub struct Triangulation<T: AsPrim + PrimInt> {
     /// A vector of point indices where each triple represents a Delaunay triangle.
@@ -118,25 +210,38 @@ pub struct Triangulation<T: AsPrim + PrimInt> {
     /// counter-clockwise.
     pub hull: Vec<T>,
 
+    /// Maps an input point to one half-edge `e` with `triangles[e] == point`.
+    /// For points on the convex `hull` this prefers a half-edge with no twin
+    /// (`halfedges[e] == empty`), so [`Triangulation::neighbors`] can walk
+    /// outward from the hull without missing a neighbor.
+    pub inedges: Vec<T>,
+
     /// Represents the area outside of the triangulation.
     /// Halfedges on the convex hull (which don't have an adjacent halfedge)
     /// will have this value.
-    pub empty: T
+    pub empty: T,
+
+    /// The advancing-front hull kept live so [`Triangulation::insert`] can
+    /// extend it without rebuilding from scratch. `hull` above is just a
+    /// snapshot of its boundary as a point-index vector.
+    hull_state: Hull<T>,
 }
 
 impl<T: AsPrim + PrimInt> Triangulation<T> {
-    fn new(points: &[Point]) -> Option<Self> {
+    pub(crate) fn new<C: Coordinate>(points: &[C]) -> Option<Self> {
         let n = points.len();
 
         let (i0, i1, i2): (T, T, T) = find_seed_triangle(points)?;
-        let center: Point = (&points[i0.as_usize()]).circumcenter(&points[i1.as_usize()], &points[i2.as_usize()]);
+        let center: Point = circumcenter(&points[i0.as_usize()], &points[i1.as_usize()], &points[i2.as_usize()]);
         let max_triangles = 2 * n - 5;
 
         let mut triangulation = Self {
             triangles: Vec::with_capacity(max_triangles * 3),
             halfedges: Vec::with_capacity(max_triangles * 3),
             hull: Vec::new(),
-            empty: T::max_value()
+            inedges: Vec::new(),
+            empty: T::max_value(),
+            hull_state: Hull::empty(),
         };
 
         let empty = triangulation.empty;
@@ -147,7 +252,7 @@ impl<T: AsPrim + PrimInt> Triangulation<T> {
         let mut dists: Vec<_> = points
             .iter()
             .enumerate()
-            .map(|(i, point)| (i, center.dist2(point)))
+            .map(|(i, point)| (i, dist2(&center, point)))
             .collect();
 
         dists.sort_unstable_by(|&(_, da), &(_, db)| da.partial_cmp(&db).unwrap());
@@ -158,7 +263,7 @@ impl<T: AsPrim + PrimInt> Triangulation<T> {
             let p = &points[iu];
 
             // skip near-duplicates
-            if k > 0 && p.nearly_equals(&points[dists[k - 1].0]) {
+            if k > 0 && nearly_equals(p, &points[dists[k - 1].0]) {
                 continue;
             }
             let i: T = iu.as_();
@@ -167,59 +272,8 @@ impl<T: AsPrim + PrimInt> Triangulation<T> {
                 continue;
             }
 
-            // find a visible edge on the convex hull using edge hash
-            let (mut e, walk_back) = hull.find_visible_edge(p, points);
-            if e == empty {
-                continue; // likely a near-duplicate point; skip it
-            }
-
-            // add the first triangle from the point
-            let t = triangulation.add_triangle(e, i, hull.next(e), empty, empty, hull.out(e));
-
-            // recursively flip triangles from the point until they satisfy the Delaunay condition
-            let out = triangulation.legalize(t + 2.as_(), points, &mut hull);
-            hull.set_out(i, out);
-            hull.set_out(e, t); // keep track of boundary triangles on the hull
-
-            // walk forward through the hull, adding more triangles and flipping recursively
-            let mut n = hull.next(e);
-            loop {
-                let q = hull.next(n);
-                if !p.orient(&points[n.as_usize()], &points[q.as_usize()]) {
-                    break;
-                }
-                let t = triangulation.add_triangle(n, i, q, hull.out(i), empty, hull.out(n));
-                let out = triangulation.legalize(t + 2.as_(), points, &mut hull);;
-                hull.set_out(i, out);
-                hull.remove(n);
-                n = q;
-            }
-
-            // walk backward from the other side, adding more triangles and flipping
-            if walk_back {
-                loop {
-                    let q = hull.prev(e);
-                    if !p.orient(&points[q.as_usize()], &points[e.as_usize()]) {
-                        break;
-                    }
-                    let t = triangulation.add_triangle(q, i, e, empty, hull.out(e), hull.out(q));
-                    triangulation.legalize(t + 2.as_(), points, &mut hull);
-                    hull.set_out(q, t);
-                    hull.remove(e);
-                    e = q;
-                }
-            }
-
-            // update the hull indices
-            hull.set_prev(i, e);
-            hull.set_next(i, n);
-            hull.set_prev(n, i);
-            hull.set_next(e, i);
-            hull.start = e;
-
-            // save the two new edges in the hash table
-            hull.hash_edge(p, i);
-            hull.hash_edge(&points[e.as_usize()], e);
+            // likely a near-duplicate point if this returns false; skip it
+            triangulation.insert_hull_point(i, &mut hull, points);
         }
 
         // expose hull as a vector of point indices
@@ -232,6 +286,19 @@ impl<T: AsPrim + PrimInt> Triangulation<T> {
             }
         }
 
+        // build the inedges table in one pass, preferring hull-boundary
+        // half-edges so hull points get an edge `neighbors` can walk from
+        let mut inedges = vec![empty; n];
+        for e in 0..triangulation.triangles.len() {
+            let e: T = e.as_();
+            let p = triangulation.origin(e);
+            if inedges[p.as_usize()] == empty || triangulation.twin(e) == empty {
+                inedges[p.as_usize()] = e;
+            }
+        }
+        triangulation.inedges = inedges;
+        triangulation.hull_state = hull;
+
         triangulation.triangles.shrink_to_fit();
         triangulation.halfedges.shrink_to_fit();
 
@@ -261,7 +328,45 @@ impl<T: AsPrim + PrimInt> Triangulation<T> {
         }
     }
 
-    fn twin(&self, halfedge_id: T) -> T {
+    /// Iterate over the points adjacent to `point`, in order around it.
+    ///
+    /// Starts from `inedges[point]` and repeatedly crosses to
+    /// `halfedges[prev_halfedge(e)]` to circle the point: `prev_halfedge(e)`
+    /// is the edge coming into `point` from the next vertex around the fan,
+    /// so its twin is the edge leaving `point` into the adjacent triangle.
+    /// For an interior point this walks all the way back around; for a point
+    /// on the convex `hull` it stops once it runs off the boundary, after
+    /// yielding the far end of the boundary edge coming into the point.
+    pub fn neighbors(&self, point: T) -> Neighbors<'_, T> {
+        let start = self.inedges[point.as_usize()];
+        Neighbors {
+            triangulation: self,
+            start,
+            current: start,
+            done: start == self.empty,
+            pending: None,
+        }
+    }
+
+    /// Add `p` to the triangulation in place, without retriangulating
+    /// everything else: locate the triangle containing it and split that
+    /// triangle into three, or - if `p` falls outside the current hull -
+    /// extend the hull to cover it. `p` is appended to `points`; every
+    /// existing index into `points`/`triangles`/`halfedges` stays valid,
+    /// so this is the entry point for streaming callers (adding samples to
+    /// a live triangulation one at a time) instead of rebuilding with
+    /// [`triangulate`] on every new point. `p` is still appended to `points`
+    /// (so its index stays predictable) even if it's a near-duplicate of a
+    /// point already in the triangulation, but the mesh itself is left
+    /// untouched for it, same as [`Triangulation::new`] skipping a
+    /// near-duplicate in its input.
+    pub fn insert(&mut self, p: Point, points: &mut Vec<Point>) {
+        let i: T = points.len().as_();
+        points.push(p);
+        self.insert_point(i, points);
+    }
+
+    pub(crate) fn twin(&self, halfedge_id: T) -> T {
         self.halfedges[halfedge_id.as_usize()]
     }
     fn set_twin(&mut self, halfedge_id: T, twin_id: T) {
@@ -270,7 +375,7 @@ impl<T: AsPrim + PrimInt> Triangulation<T> {
         }
     }
 
-    fn origin(&self, halfedge_id: T) -> T {
+    pub(crate) fn origin(&self, halfedge_id: T) -> T {
         self.triangles[halfedge_id.as_usize()]
     }
     fn set_origin(&mut self, halfedge_id: T, point_id: T) {
@@ -302,7 +407,7 @@ impl<T: AsPrim + PrimInt> Triangulation<T> {
         t
     }
 
-    fn legalize(&mut self, a: T, points: &[Point], hull: &mut Hull<T>) -> T {
+    fn legalize<C: Coordinate>(&mut self, a: T, points: &[C], hull: &mut Hull<T>) -> T {
         let b = self.twin(a);
 
         // if the pair of triangles doesn't satisfy the Delaunay condition
@@ -334,26 +439,14 @@ impl<T: AsPrim + PrimInt> Triangulation<T> {
         let pl = self.origin(al);
         let p1 = self.origin(bl);
 
-        let illegal = (&points[p0.as_usize()]).in_circle(&points[pr.as_usize()], &points[pl.as_usize()], &points[p1.as_usize()]);
+        let illegal = in_circle(&points[p0.as_usize()], &points[pr.as_usize()], &points[pl.as_usize()], &points[p1.as_usize()]);
         if illegal {
-            self.set_origin(a, p1);
-            self.set_origin(b, p0);
-
-            let hbl = self.twin(bl);
-            let har = self.twin(ar);
-
             // edge swapped on the other side of the hull (rare); fix the halfedge reference
-            if hbl == self.empty {
+            if self.twin(bl) == self.empty {
                 hull.fix_halfedge(bl, a);
             }
 
-            self.set_twin(a, hbl);
-            self.set_twin(b, har);
-            self.set_twin(ar, bl);
-
-            self.set_twin(hbl, a);
-            self.set_twin(har, b);
-            self.set_twin(bl, ar);
+            self.flip_edge(a);
 
             let br = Self::next_halfedge(b);
 
@@ -362,6 +455,341 @@ impl<T: AsPrim + PrimInt> Triangulation<T> {
         }
         ar
     }
+
+    /// Swap the diagonal of the two triangles sharing half-edge `a`: the
+    /// edge `origin(a)-origin(twin(a))` is replaced by the edge joining the
+    /// two triangles' opposite vertices. `a`'s twin must not be `empty`.
+    ///
+    /// This is the mutation [`Triangulation::legalize`] performs once it
+    /// decides a pair of triangles is illegal; [`Triangulation::constrain`]
+    /// reuses it unconditionally to force constraint edges into the mesh.
+    pub(crate) fn flip_edge(&mut self, a: T) {
+        let b = self.twin(a);
+
+        let ar = Self::prev_halfedge(a);
+        let al = Self::next_halfedge(a);
+        let bl = Self::prev_halfedge(b);
+
+        let p0 = self.origin(ar);
+        let p1 = self.origin(bl);
+        let pr = self.origin(a);
+        let pl = self.origin(b);
+
+        self.set_origin(a, p1);
+        self.set_origin(b, p0);
+
+        // `a`/`b` no longer originate at `pr`/`pl`; if `inedges` is already
+        // populated (i.e. we're past `Triangulation::new` and flipping via
+        // an [`Triangulation::insert`]-triggered `legalize`), repair the
+        // entries that pointed at them, using the edges that still do
+        // (`al`, unaffected by this flip, and `next(b)`, same reasoning)
+        if !self.inedges.is_empty() {
+            if self.inedges[pr.as_usize()] == a {
+                self.inedges[pr.as_usize()] = Self::next_halfedge(b);
+            }
+            if self.inedges[pl.as_usize()] == b {
+                self.inedges[pl.as_usize()] = al;
+            }
+        }
+
+        let hbl = self.twin(bl);
+        let har = self.twin(ar);
+
+        self.set_twin(a, hbl);
+        self.set_twin(b, har);
+        self.set_twin(ar, bl);
+
+        self.set_twin(hbl, a);
+        self.set_twin(har, b);
+        self.set_twin(bl, ar);
+    }
+
+    /// Add `p` to a triangulation built by [`Triangulation::new`] without
+    /// retriangulating: locate the triangle (if any) containing it and
+    /// split that triangle into three, or extend the live hull if `p`
+    /// falls outside it. `points` must already have `p` pushed onto it;
+    /// callers go through [`Triangulation::insert`] instead of this. Like
+    /// [`Triangulation::new`], silently skips `p` (leaving its `inedges`
+    /// entry at `empty`, same as a point `new()` skipped) if it's a
+    /// near-duplicate of a point already in the triangulation, rather than
+    /// splitting a triangle with `p` sitting on an existing vertex.
+    fn insert_point(&mut self, i: T, points: &[Point]) {
+        self.inedges.push(self.empty);
+        self.hull_state.grow();
+
+        if let Some(e0) = self.locate(i, points) {
+            let e1 = Self::next_halfedge(e0);
+            let e2 = Self::next_halfedge(e1);
+            let p = &points[i.as_usize()];
+            let a = self.origin(e0);
+            let b = self.origin(e1);
+            let c = self.origin(e2);
+            if nearly_equals(p, &points[a.as_usize()])
+                || nearly_equals(p, &points[b.as_usize()])
+                || nearly_equals(p, &points[c.as_usize()])
+            {
+                return;
+            }
+            self.split_triangle(e0, i, points);
+            return;
+        }
+
+        let mut hull = std::mem::replace(&mut self.hull_state, Hull::empty());
+        let before: T = self.triangles.len().as_();
+        if self.insert_hull_point(i, &mut hull, points) {
+            let after: T = self.triangles.len().as_();
+            self.fix_inedges(before, after);
+
+            // refresh the exposed `hull` boundary from the live `hull_state`
+            let mut boundary = Vec::new();
+            let mut e = hull.start;
+            loop {
+                boundary.push(e);
+                e = hull.next(e);
+                if e == hull.start {
+                    break;
+                }
+            }
+            self.hull = boundary;
+        }
+        self.hull_state = hull;
+    }
+
+    /// Add point `i` from `points` to the triangulation, growing the hull:
+    /// find the hull edge it's visible from, fan triangles out from it
+    /// walking forward and (if needed) backward along the hull, legalizing
+    /// each new triangle, then splice `i` into `hull`'s boundary. Shared by
+    /// [`Triangulation::new`] (every point starts out beyond the hull built
+    /// so far) and [`Triangulation::insert_point`]. Returns `false` without
+    /// changing anything if `i` is a near-duplicate of a hull point the
+    /// edge hash can't see past.
+    fn insert_hull_point<C: Coordinate>(&mut self, i: T, hull: &mut Hull<T>, points: &[C]) -> bool {
+        let empty = self.empty;
+        let p = &points[i.as_usize()];
+
+        // find a visible edge on the convex hull using edge hash
+        let (mut e, walk_back) = hull.find_visible_edge(p, points);
+        if e == empty {
+            return false; // likely a near-duplicate point; skip it
+        }
+
+        // add the first triangle from the point
+        let t = self.add_triangle(e, i, hull.next(e), empty, empty, hull.out(e));
+
+        // recursively flip triangles from the point until they satisfy the Delaunay condition
+        let out = self.legalize(t + 2.as_(), points, hull);
+        hull.set_out(i, out);
+        hull.set_out(e, t); // keep track of boundary triangles on the hull
+
+        // walk forward through the hull, adding more triangles and flipping recursively
+        let mut n = hull.next(e);
+        loop {
+            let q = hull.next(n);
+            if !orient(p, &points[n.as_usize()], &points[q.as_usize()]) {
+                break;
+            }
+            let t = self.add_triangle(n, i, q, hull.out(i), empty, hull.out(n));
+            let out = self.legalize(t + 2.as_(), points, hull);
+            hull.set_out(i, out);
+            hull.remove(n);
+            n = q;
+        }
+
+        // walk backward from the other side, adding more triangles and flipping
+        if walk_back {
+            loop {
+                let q = hull.prev(e);
+                if !orient(p, &points[q.as_usize()], &points[e.as_usize()]) {
+                    break;
+                }
+                let t = self.add_triangle(q, i, e, empty, hull.out(e), hull.out(q));
+                self.legalize(t + 2.as_(), points, hull);
+                hull.set_out(q, t);
+                hull.remove(e);
+                e = q;
+            }
+        }
+
+        // update the hull indices
+        hull.set_prev(i, e);
+        hull.set_next(i, n);
+        hull.set_prev(n, i);
+        hull.set_next(e, i);
+        hull.start = e;
+
+        // save the two new edges in the hash table
+        hull.hash_edge(p, i);
+        hull.hash_edge(&points[e.as_usize()], e);
+
+        true
+    }
+
+    /// The triangle containing `p`, found by walking the half-edge graph:
+    /// starting from an arbitrary triangle, step across whichever edge's
+    /// [`orient`] test puts `p` outside it, until all three edges of the
+    /// current triangle have `p` on the inside (or exactly on an edge).
+    /// Returns the id of that triangle's first half-edge, or `None` if the
+    /// walk runs off the hull boundary (`p` lies outside the hull).
+    fn locate(&self, p: T, points: &[Point]) -> Option<T> {
+        let mut e0: T = 0.as_();
+        let mut budget = self.triangles.len() + 1;
+
+        loop {
+            let e1 = Self::next_halfedge(e0);
+            let e2 = Self::next_halfedge(e1);
+
+            let a = self.origin(e0);
+            let b = self.origin(e1);
+            let c = self.origin(e2);
+
+            let outside = if orient(&points[a.as_usize()], &points[b.as_usize()], &points[p.as_usize()]) {
+                Some(e0)
+            } else if orient(&points[b.as_usize()], &points[c.as_usize()], &points[p.as_usize()]) {
+                Some(e1)
+            } else if orient(&points[c.as_usize()], &points[a.as_usize()], &points[p.as_usize()]) {
+                Some(e2)
+            } else {
+                None
+            };
+
+            match outside {
+                Some(edge) => {
+                    let twin = self.twin(edge);
+                    if twin == self.empty {
+                        return None;
+                    }
+                    if budget == 0 {
+                        return None;
+                    }
+                    budget -= 1;
+                    e0 = twin;
+                }
+                None => return Some(e0),
+            }
+        }
+    }
+
+    /// Split the triangle whose first half-edge is `e0` into three by
+    /// connecting each of its vertices to the new point `p`: one new
+    /// triangle reuses `e0`'s slot, the other two are appended. Then
+    /// legalize the three edges bordering the rest of the mesh - the only
+    /// ones that can have become illegal, since every edge touching `p` is
+    /// brand new and trivially satisfies the Delaunay condition.
+    fn split_triangle(&mut self, e0: T, p: T, points: &[Point]) {
+        let e1 = Self::next_halfedge(e0);
+        let e2 = Self::next_halfedge(e1);
+
+        let a = self.origin(e0);
+        let b = self.origin(e1);
+        let c = self.origin(e2);
+
+        let tb = self.twin(e1);
+        let tc = self.twin(e2);
+
+        let t1: T = self.triangles.len().as_();
+        let t2: T = t1 + 3.as_();
+
+        // reuse e0's triangle in place as (a, b, p); edge a-b doesn't move
+        // (it keeps its existing twin), the other two now border the two
+        // appended triangles
+        self.set_origin(e2, p);
+        self.set_twin(e1, t1 + 2.as_());
+        self.set_twin(e2, t2 + 1.as_());
+
+        // append (b, c, p), reusing tb as its outward edge
+        self.triangles.push(b);
+        self.triangles.push(c);
+        self.triangles.push(p);
+        self.halfedges.push(tb);
+        self.halfedges.push(t2 + 2.as_());
+        self.halfedges.push(e1);
+        self.set_twin(tb, t1);
+
+        // append (c, a, p), reusing tc as its outward edge
+        self.triangles.push(c);
+        self.triangles.push(a);
+        self.triangles.push(p);
+        self.halfedges.push(tc);
+        self.halfedges.push(e2);
+        self.halfedges.push(t1 + 1.as_());
+        self.set_twin(tc, t2);
+
+        // `c`'s inedge may have pointed at e2, which used to originate at
+        // `c` and now originates at `p`
+        if self.inedges[c.as_usize()] == e2 {
+            self.inedges[c.as_usize()] = t2;
+        }
+        self.inedges[p.as_usize()] = e2;
+        self.fix_inedges(t1, t2 + 3.as_());
+
+        // if `b` or `c` is on the hull, its `Hull::out` entry may be
+        // pointing at the b-c/c-a edge we just relocated to t1/t2
+        let mut hull = std::mem::replace(&mut self.hull_state, Hull::empty());
+        if hull.out(b) == e1 {
+            hull.set_out(b, t1);
+        }
+        if hull.out(c) == e2 {
+            hull.set_out(c, t2);
+        }
+
+        self.legalize(e0, points, &mut hull);
+        self.legalize(t1, points, &mut hull);
+        self.legalize(t2, points, &mut hull);
+        self.hull_state = hull;
+    }
+
+    /// Point every origin touched by half-edges `[from, to)` at one of
+    /// those half-edges, preferring one with no twin (a hull-boundary
+    /// edge) the same way [`Triangulation::new`]'s one-shot `inedges` pass
+    /// does, but scoped to the edges a single [`Triangulation::insert`]
+    /// call just added.
+    fn fix_inedges(&mut self, from: T, to: T) {
+        let mut e = from.as_usize();
+        let to = to.as_usize();
+        while e < to {
+            let ei: T = e.as_();
+            let origin = self.origin(ei);
+            if self.inedges[origin.as_usize()] == self.empty || self.twin(ei) == self.empty {
+                self.inedges[origin.as_usize()] = ei;
+            }
+            e += 1;
+        }
+    }
+}
+
+/// Iterator over the points adjacent to a point, created by
+/// [`Triangulation::neighbors`].
+pub struct Neighbors<'a, T: AsPrim + PrimInt> {
+    triangulation: &'a Triangulation<T>,
+    start: T,
+    current: T,
+    done: bool,
+    pending: Option<T>,
+}
+
+impl<'a, T: AsPrim + PrimInt> Iterator for Neighbors<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.done {
+            return self.pending.take();
+        }
+        let next_he = Triangulation::<T>::next_halfedge(self.current);
+        let neighbor = self.triangulation.origin(next_he);
+        let prev_he = Triangulation::<T>::prev_halfedge(self.current);
+        let twin = self.triangulation.twin(prev_he);
+        if twin == self.triangulation.empty {
+            // a hull point's last neighbor is the origin of its incoming
+            // boundary edge, which no outgoing edge of the fan reaches
+            self.pending = Some(self.triangulation.origin(prev_he));
+            self.done = true;
+        } else if twin == self.start {
+            self.done = true;
+        } else {
+            self.current = twin;
+        }
+        Some(neighbor)
+    }
 }
 
 /// data structure for tracking the edges of the advancing convex hull
@@ -388,7 +816,7 @@ struct Hull<T: AsPrim + PrimInt> {
 }
 
 impl<T: PrimInt + AsPrim> Hull<T> {
-    fn new(n: usize, center: Point, i0: T, i1: T, i2: T, points: &[Point]) -> Self {
+    fn new<C: Coordinate>(n: usize, center: Point, i0: T, i1: T, i2: T, points: &[C]) -> Self {
         let hash_len = (n as f64).sqrt() as usize;
 
         let empty = T::max_value();
@@ -421,6 +849,32 @@ impl<T: PrimInt + AsPrim> Hull<T> {
         hull
     }
 
+    /// A placeholder hull with no points, only ever used to fill the gap
+    /// left in [`Triangulation::hull_state`] while the real one is taken
+    /// out (via [`std::mem::replace`]) to get around it and the
+    /// triangulation it belongs to both needing `&mut self` at once.
+    fn empty() -> Self {
+        let empty = T::max_value();
+        Self {
+            prev: Vec::new(),
+            next: Vec::new(),
+            out: Vec::new(),
+            hash: Vec::new(),
+            start: empty,
+            center: Point { x: 0.0, y: 0.0 },
+            empty,
+        }
+    }
+
+    /// Extend `prev`/`next`/`out` by one slot for a newly inserted point,
+    /// so it can be indexed by point id before it's necessarily known
+    /// whether that point ends up on the hull.
+    fn grow(&mut self) {
+        self.prev.push(T::zero());
+        self.next.push(T::zero());
+        self.out.push(T::zero());
+    }
+
     fn out(&self, point_id: T) -> T {
         self.out[point_id.as_usize()]
     }
@@ -447,9 +901,9 @@ impl<T: PrimInt + AsPrim> Hull<T> {
         self.set_next(point_id, empty); // mark as removed
     }
 
-    fn hash_key(&self, p: &Point) -> usize {
-        let dx = p.x - self.center.x;
-        let dy = p.y - self.center.y;
+    fn hash_key<C: Coordinate>(&self, p: &C) -> usize {
+        let dx = p.x() - self.center.x;
+        let dy = p.y() - self.center.y;
 
         let p = dx / (dx.abs() + dy.abs());
         let a = (if dy > 0.0 { 3.0 - p } else { 1.0 + p }) / 4.0; // [0..1]
@@ -458,12 +912,12 @@ impl<T: PrimInt + AsPrim> Hull<T> {
         (((len as f64) * a).floor() as usize) % len
     }
 
-    fn hash_edge(&mut self, p: &Point, i: T) {
+    fn hash_edge<C: Coordinate>(&mut self, p: &C, i: T) {
         let key = self.hash_key(p);
         self.hash[key] = i;
     }
 
-    fn find_visible_edge(&self, p: &Point, points: &[Point]) -> (T, bool) {
+    fn find_visible_edge<C: Coordinate>(&self, p: &C, points: &[C]) -> (T, bool) {
         let mut start: T = 0.as_();
         let key = self.hash_key(p);
         let len = self.hash.len();
@@ -476,7 +930,7 @@ impl<T: PrimInt + AsPrim> Hull<T> {
         start = self.prev(start);
         let mut e = start;
 
-        while !p.orient(&points[e.as_usize()], &points[self.next(e).as_usize()]) {
+        while !orient(p, &points[e.as_usize()], &points[self.next(e).as_usize()]) {
             e = self.next(e);
             if e == start {
                 return (self.empty, false);
@@ -500,22 +954,22 @@ impl<T: PrimInt + AsPrim> Hull<T> {
     }
 }
 
-fn calc_bbox_center(points: &[Point]) -> Point {
-    let min_x = points.iter().fold(f64::INFINITY, |acc, p| acc.min(p.x));
-    let min_y = points.iter().fold(f64::INFINITY, |acc, p| acc.min(p.y));
-    let max_x = points.iter().fold(f64::NEG_INFINITY, |acc, p| acc.max(p.x));
-    let max_y = points.iter().fold(f64::NEG_INFINITY, |acc, p| acc.max(p.y));
+fn calc_bbox_center<C: Coordinate>(points: &[C]) -> Point {
+    let min_x = points.iter().fold(f64::INFINITY, |acc, p| acc.min(p.x()));
+    let min_y = points.iter().fold(f64::INFINITY, |acc, p| acc.min(p.y()));
+    let max_x = points.iter().fold(f64::NEG_INFINITY, |acc, p| acc.max(p.x()));
+    let max_y = points.iter().fold(f64::NEG_INFINITY, |acc, p| acc.max(p.y()));
     Point {
         x: (min_x + max_x) / 2.0,
         y: (min_y + max_y) / 2.0,
     }
 }
 
-fn find_closest_point(points: &[Point], p0: &Point) -> Option<usize> {
+fn find_closest_point<C: Coordinate, P: Coordinate>(points: &[C], p0: &P) -> Option<usize> {
     let mut min_dist = f64::INFINITY;
     let mut k: usize = 0;
     for (i, p) in points.iter().enumerate() {
-        let d = p0.dist2(p);
+        let d = dist2(p0, p);
         if d > 0.0 && d < min_dist {
             k = i;
             min_dist = d;
@@ -528,7 +982,7 @@ fn find_closest_point(points: &[Point], p0: &Point) -> Option<usize> {
     }
 }
 
-fn find_seed_triangle<T: AsPrim + PrimInt>(points: &[Point]) -> Option<(T, T, T)> {
+fn find_seed_triangle<T: AsPrim + PrimInt, C: Coordinate>(points: &[C]) -> Option<(T, T, T)> {
     // pick a seed point close to the center
     let bbox_center = calc_bbox_center(points);
     let i0 = find_closest_point(points, &bbox_center)?;
@@ -545,7 +999,7 @@ fn find_seed_triangle<T: AsPrim + PrimInt>(points: &[Point]) -> Option<(T, T, T)
         if i == i0 || i == i1 {
             continue;
         }
-        let r = p0.circumradius2(p1, p);
+        let r = circumradius2(p0, p1, p);
         if r < min_radius {
             i2 = i;
             min_radius = r;
@@ -556,7 +1010,7 @@ fn find_seed_triangle<T: AsPrim + PrimInt>(points: &[Point]) -> Option<(T, T, T)
         None
     } else {
         // swap the order of the seed points for counter-clockwise orientation
-        Some(if p0.orient(p1, &points[i2]) {
+        Some(if orient(p0, p1, &points[i2]) {
             (i0.as_(), i2.as_(), i1.as_())
         } else {
             (i0.as_(), i1.as_(), i2.as_())
@@ -566,6 +1020,41 @@ fn find_seed_triangle<T: AsPrim + PrimInt>(points: &[Point]) -> Option<(T, T, T)
 
 /// Triangulate a set of 2D points.
 /// Returns `None` if no triangulation exists for the input (e.g. all points are collinear).
-pub fn triangulate(points: &[Point]) -> Option<Triangulation<u32>> {
+///
+/// `points` can be a slice of [`Point`], or of any type implementing
+/// [`Coordinate`] (e.g. `[f64; 2]` or `(f64, f64)`).
+pub fn triangulate<C: Coordinate>(points: &[C]) -> Option<Triangulation<u32>> {
     Triangulation::new(points)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A square with a center point: every one of the 4 triangles touches
+    /// the center, so `neighbors(4)` should walk the whole fan and return
+    /// all 4 corners.
+    #[test]
+    fn neighbors_walks_the_full_fan_around_an_interior_point() {
+        let points = vec![
+            Point { x: 0., y: 0. },
+            Point { x: 1., y: 0. },
+            Point { x: 1., y: 1. },
+            Point { x: 0., y: 1. },
+            Point { x: 0.5, y: 0.5 },
+        ];
+        let t = triangulate(&points).unwrap();
+
+        let mut center_neighbors: Vec<u32> = t.neighbors(4).collect();
+        center_neighbors.sort();
+        assert_eq!(center_neighbors, vec![0, 1, 2, 3]);
+
+        // every corner should see the center plus its two square neighbors
+        for corner in 0..4u32 {
+            let mut corner_neighbors: Vec<u32> = t.neighbors(corner).collect();
+            corner_neighbors.sort();
+            assert!(corner_neighbors.contains(&4));
+            assert!(corner_neighbors.len() >= 2);
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! Adaptive exact geometric predicates, Shewchuk-style.
+//!
+//! [`crate::orient`] and [`crate::in_circle`] evaluate their determinant in
+//! plain `f64` and compare the result against a forward error bound derived
+//! from the magnitude of the terms involved; only when the result is too
+//! small to trust (near-collinear or near-cocircular input, common with
+//! grid-aligned or clustered points) do they fall back to the exact
+//! determinants computed here. The fast float path is still what runs for
+//! ordinary input, so this only costs anything on the inputs that used to
+//! misbehave.
+//!
+//! The exact fallback is built from IEEE-754 "expansion" arithmetic
+//! (Shewchuk, *Adaptive Precision Floating-Point Arithmetic and Fast Robust
+//! Geometric Predicates*, 1997): a sum of `f64`s is represented exactly as a
+//! non-overlapping, increasing-magnitude sequence of `f64` components, and
+//! [`two_sum`]/[`two_product`] compute such a sequence for a single
+//! addition/multiplication without any rounding error. [`grow_expansion`]
+//! folds one more scalar into an existing expansion; every other operation
+//! here ([`expansion_sum`], [`scale_expansion`], [`expansion_product`]) is
+//! built out of it. The sign of the resulting expansion's most significant
+//! (last) component is the exact sign of the whole sum. These primitives
+//! rely on IEEE-754 round-to-nearest semantics and assume `a * b + c` is
+//! never contracted into a fused multiply-add, which rustc does not do
+//! implicitly.
+
+use crate::Coordinate;
+
+const SPLITTER: f64 = 134217729.0; // 2^27 + 1, Veltkamp's splitting constant
+
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let bvirt = x - a;
+    let avirt = x - bvirt;
+    let bround = b - bvirt;
+    let around = a - avirt;
+    (x, around + bround)
+}
+
+fn two_diff(a: f64, b: f64) -> (f64, f64) {
+    let x = a - b;
+    let bvirt = a - x;
+    let avirt = x + bvirt;
+    let bround = bvirt - b;
+    let around = a - avirt;
+    (x, around + bround)
+}
+
+fn split(a: f64) -> (f64, f64) {
+    let c = SPLITTER * a;
+    let abig = c - a;
+    let ahi = c - abig;
+    let alo = a - ahi;
+    (ahi, alo)
+}
+
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    let (ahi, alo) = split(a);
+    let (bhi, blo) = split(b);
+    let err1 = x - (ahi * bhi);
+    let err2 = err1 - (alo * bhi);
+    let err3 = err2 - (ahi * blo);
+    (x, (alo * blo) - err3)
+}
+
+/// The exact difference `a - b`, as a non-overlapping expansion.
+fn diff_expansion(a: f64, b: f64) -> Vec<f64> {
+    let (hi, lo) = two_diff(a, b);
+    if lo != 0.0 {
+        vec![lo, hi]
+    } else {
+        vec![hi]
+    }
+}
+
+/// Fold scalar `x` into the valid expansion `e`, returning a new valid
+/// expansion with the same exact value as `e`'s sum plus `x`. Zero
+/// components are dropped, including the final running sum - unless that
+/// would leave the expansion empty, since [`sign`] needs at least one
+/// component (a zero one, meaning the exact value is zero) to report `0`.
+fn grow_expansion(e: &[f64], x: f64) -> Vec<f64> {
+    let mut out = Vec::with_capacity(e.len() + 1);
+    let mut q = x;
+    for &ei in e {
+        let (sum, err) = two_sum(q, ei);
+        if err != 0.0 {
+            out.push(err);
+        }
+        q = sum;
+    }
+    if q != 0.0 || out.is_empty() {
+        out.push(q);
+    }
+    out
+}
+
+/// The exact sum of two expansions, as a new expansion.
+fn expansion_sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut out = e.to_vec();
+    for &fi in f {
+        out = grow_expansion(&out, fi);
+    }
+    out
+}
+
+fn negate(e: &[f64]) -> Vec<f64> {
+    e.iter().map(|v| -v).collect()
+}
+
+/// The exact product of expansion `e` and scalar `b`, as a new expansion.
+fn scale_expansion(e: &[f64], b: f64) -> Vec<f64> {
+    let mut out = Vec::new();
+    for &ei in e {
+        let (hi, lo) = two_product(ei, b);
+        if lo != 0.0 {
+            out = grow_expansion(&out, lo);
+        }
+        if hi != 0.0 {
+            out = grow_expansion(&out, hi);
+        }
+    }
+    out
+}
+
+/// The exact product of two expansions, as a new expansion.
+fn expansion_product(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut out = Vec::new();
+    for &fi in f {
+        out = expansion_sum(&out, &scale_expansion(e, fi));
+    }
+    out
+}
+
+/// The sign of a valid expansion's exact sum: `1` positive, `-1` negative,
+/// `0` exactly zero.
+fn sign(e: &[f64]) -> i32 {
+    match e.last() {
+        None => 0,
+        Some(v) if *v > 0.0 => 1,
+        Some(v) if *v < 0.0 => -1,
+        Some(_) => 0,
+    }
+}
+
+/// Exact sign of `(q.y-p.y)*(r.x-q.x) - (q.x-p.x)*(r.y-q.y)`, the same
+/// determinant [`crate::orient`] evaluates in floating point.
+pub(crate) fn orient_exact<C: Coordinate>(p: &C, q: &C, r: &C) -> i32 {
+    let qy_py = diff_expansion(q.y(), p.y());
+    let rx_qx = diff_expansion(r.x(), q.x());
+    let qx_px = diff_expansion(q.x(), p.x());
+    let ry_qy = diff_expansion(r.y(), q.y());
+
+    let left = expansion_product(&qy_py, &rx_qx);
+    let right = expansion_product(&qx_px, &ry_qy);
+
+    sign(&expansion_sum(&left, &negate(&right)))
+}
+
+/// Exact sign of `dx*(ey*cp-bp*fy) - dy*(ex*cp-bp*fx) + ap*(ex*fy-ey*fx)`
+/// (`d../e../f..` relative to `p`, `ap`/`bp`/`cp` their squared distances to
+/// `p`), the same determinant [`crate::in_circle`] evaluates in floating
+/// point.
+pub(crate) fn in_circle_exact<C: Coordinate>(a: &C, b: &C, c: &C, p: &C) -> i32 {
+    let dx = diff_expansion(a.x(), p.x());
+    let dy = diff_expansion(a.y(), p.y());
+    let ex = diff_expansion(b.x(), p.x());
+    let ey = diff_expansion(b.y(), p.y());
+    let fx = diff_expansion(c.x(), p.x());
+    let fy = diff_expansion(c.y(), p.y());
+
+    let ap = expansion_sum(&expansion_product(&dx, &dx), &expansion_product(&dy, &dy));
+    let bp = expansion_sum(&expansion_product(&ex, &ex), &expansion_product(&ey, &ey));
+    let cp = expansion_sum(&expansion_product(&fx, &fx), &expansion_product(&fy, &fy));
+
+    let term_a = expansion_sum(&expansion_product(&ey, &cp), &negate(&expansion_product(&bp, &fy)));
+    let term_b = expansion_sum(&expansion_product(&ex, &cp), &negate(&expansion_product(&bp, &fx)));
+    let term_c = expansion_sum(&expansion_product(&ex, &fy), &negate(&expansion_product(&ey, &fx)));
+
+    let total = expansion_sum(
+        &expansion_sum(&expansion_product(&dx, &term_a), &negate(&expansion_product(&dy, &term_b))),
+        &expansion_product(&ap, &term_c),
+    );
+
+    sign(&total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A zero running sum shouldn't clobber a nonzero lower-magnitude
+    /// component already in the expansion: the true value here is `1e-300`
+    /// (positive), not `0`.
+    #[test]
+    fn grow_expansion_drops_a_zero_final_component() {
+        let e = grow_expansion(&[1e-300, 1.0], -1.0);
+        assert_eq!(sign(&e), 1);
+    }
+}
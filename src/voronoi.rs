@@ -0,0 +1,132 @@
+//! The Voronoi diagram dual to a [`Triangulation`].
+
+use asprim::AsPrim;
+use num_traits::int::PrimInt;
+
+use crate::{circumcenter, Coordinate, Point, Triangulation};
+
+/// The Voronoi diagram dual to a Delaunay [`Triangulation`], built by
+/// [`Triangulation::voronoi`].
+///
+/// Each Voronoi vertex is the circumcenter of a Delaunay triangle, and each
+/// cell is the polygon of circumcenters surrounding one input point.
+pub struct Voronoi {
+    /// One Voronoi vertex per triangle: the triangle's circumcenter.
+    pub circumcenters: Vec<Point>,
+
+    /// Cell polygons indexed by input point.
+    ///
+    /// For an interior point this is a closed polygon of circumcenters. For
+    /// a point on the convex hull the cell is unbounded: the first and last
+    /// entries are direction vectors (not absolute positions) for the
+    /// outward rays perpendicular to the two hull edges meeting at that
+    /// point, with the bounding circumcenters in between. Points that were
+    /// skipped during triangulation (near-duplicates) get an empty cell.
+    pub cells: Vec<Vec<Point>>,
+}
+
+impl<T: AsPrim + PrimInt> Triangulation<T> {
+    /// Build the [`Voronoi`] diagram dual to this triangulation. `points`
+    /// only needs to implement [`Coordinate`], same as [`Triangulation::new`]:
+    /// the points this triangulation was built from don't need to be copied
+    /// into [`Point`]s first.
+    pub fn voronoi<C: Coordinate>(&self, points: &[C]) -> Voronoi {
+        let circumcenters: Vec<Point> = (0..self.len())
+            .map(|t| {
+                let a = &points[self.triangles[3 * t].as_usize()];
+                let b = &points[self.triangles[3 * t + 1].as_usize()];
+                let c = &points[self.triangles[3 * t + 2].as_usize()];
+                circumcenter(a, b, c)
+            })
+            .collect();
+
+        let hull_len = self.hull.len();
+        let cells = (0..points.len())
+            .map(|p| {
+                let p: T = p.as_();
+                if self.inedges[p.as_usize()] == self.empty {
+                    return Vec::new();
+                }
+                match self.hull.iter().position(|&h| h == p) {
+                    Some(idx) => self.hull_cell(p, idx, hull_len, points, &circumcenters),
+                    None => self.interior_cell(p, &circumcenters),
+                }
+            })
+            .collect();
+
+        Voronoi {
+            circumcenters,
+            cells,
+        }
+    }
+
+    /// The cell of an interior point: the circumcenters of every triangle
+    /// incident to it, in order around the point.
+    fn interior_cell(&self, p: T, circumcenters: &[Point]) -> Vec<Point> {
+        let start = self.inedges[p.as_usize()];
+        let mut cell = Vec::new();
+        let mut e = start;
+        loop {
+            cell.push(circumcenters[e.as_usize() / 3].clone());
+            let twin = self.twin(Self::prev_halfedge(e));
+            if twin == self.empty || twin == start {
+                break;
+            }
+            e = twin;
+        }
+        cell
+    }
+
+    /// The unbounded cell of a hull point: the circumcenters of the
+    /// triangles incident to it, bracketed by outward rays perpendicular to
+    /// the two hull edges meeting at the point.
+    fn hull_cell<C: Coordinate>(
+        &self,
+        p: T,
+        hull_idx: usize,
+        hull_len: usize,
+        points: &[C],
+        circumcenters: &[Point],
+    ) -> Vec<Point> {
+        let start = self.inedges[p.as_usize()];
+        let mut fan = Vec::new();
+        let mut e = start;
+        loop {
+            fan.push(circumcenters[e.as_usize() / 3].clone());
+            let twin = self.twin(Self::prev_halfedge(e));
+            if twin == self.empty {
+                break;
+            }
+            e = twin;
+        }
+
+        let this_point = &points[p.as_usize()];
+        let prev_point = &points[self.hull[(hull_idx + hull_len - 1) % hull_len].as_usize()];
+        let next_point = &points[self.hull[(hull_idx + 1) % hull_len].as_usize()];
+
+        // `fan` was walked from `inedges[p]` (the next-side boundary edge)
+        // towards the prev-side one, but the cell runs prev-side -> next-side
+        // to match the flanking rays, so reverse it
+        fan.reverse();
+
+        let mut cell = Vec::with_capacity(fan.len() + 2);
+        cell.push(outward_ray(prev_point, this_point));
+        cell.extend(fan);
+        cell.push(outward_ray(this_point, next_point));
+        cell
+    }
+}
+
+/// A unit direction vector perpendicular to hull edge `a -> b`, pointing
+/// away from the triangulation. The triangles lie on the side of every hull
+/// edge that [`crate::orient`] reports `false` for, i.e. along `(dy, -dx)`,
+/// so the outward normal is `(-dy, dx)`.
+fn outward_ray<C: Coordinate>(a: &C, b: &C) -> Point {
+    let dx = b.x() - a.x();
+    let dy = b.y() - a.y();
+    let len = (dx * dx + dy * dy).sqrt();
+    Point {
+        x: -dy / len,
+        y: dx / len,
+    }
+}